@@ -11,8 +11,27 @@
 //! - `move var` (moves `var` into the closure)
 //! - `ref var` (borrows `var`)
 //! - `ref mut var` (mutably borrows `var`)
-//! - `$IDENT var` (transforms `var` where $IDENT is any identifier for a method
-//! with a `self` receiver and no further arguments)
+//! - `$IDENT var` (transforms `var` where $IDENT is any identifier for a
+//!   method with a `self` receiver and no further arguments)
+//! - `weak var` (downgrades an `Rc`/`Arc` to a `Weak` handle before the
+//!   closure is built, and re-upgrades it at the start of every call, see
+//!   [Weak Captures](#weak-captures))
+//!
+//! Every capture form above can additionally be suffixed with `as <ident>` to
+//! bind the captured value under a name of your choosing instead of the last
+//! identifier of the capture's path (see [Renaming a Capture](#renaming-a-capture)).
+//!
+//! `move`, `ref`, `ref mut` and `weak` are reserved capture keywords and take
+//! precedence over the generic `$IDENT var` transform: `weak var` always
+//! downgrades and re-upgrades `var` and never calls a method literally named
+//! `weak` on it, the same way `move var` never calls a method named `move`
+//! (see [$IDENT-transform Binding](#ident-transform-binding)).
+//!
+//! Moving a nested path such as `move var.field` only moves that one field
+//! out of `var`, the same as writing `let field = var.field;` by hand would,
+//! so sibling fields of `var` remain usable afterwards (see the nested-path
+//! examples under [Reference Binding](#reference-binding) and
+//! [Disjoint Field Captures](#disjoint-field-captures)).
 //!
 //! ## Move Binding
 //!
@@ -80,6 +99,10 @@
 //! The most common use case for this type of capture is probably for calling
 //! `clone()` on a variable, but any method conforming to the aforementioned
 //! rules is also possible, such as `to_string`, `to_owned`, `into_iter`, etc.
+//! `move`, `ref` and `weak` are reserved and cannot be used as `$IDENT` here;
+//! a variable with, say, an inherent `.weak()` method cannot be captured by
+//! calling it this way and must be transformed by hand before the `closure!`
+//! call instead.
 //!
 //! ```
 //! # use closure::closure;
@@ -98,6 +121,29 @@
 //! println!("the original {} and {} were not moved", first, second);
 //! ```
 //!
+//! ## Renaming a Capture
+//!
+//! By default, a capture is bound under the last identifier of its path, e.g.
+//! `clone self.inner` is bound as `inner`. Appending `as <ident>` after the
+//! path overrides this and binds the capture under the given name instead,
+//! which is especially useful when two captures would otherwise collide on
+//! the same trailing field name:
+//!
+//! ```
+//! # use closure::closure;
+//! struct Wrapper {
+//!     inner: i32,
+//! }
+//!
+//! let a = Wrapper { inner: 1 };
+//! let b = Wrapper { inner: 2 };
+//!
+//! let closure = closure!(move a.inner as a_inner, move b.inner as b_inner, || {
+//!     a_inner + b_inner
+//! });
+//! assert_eq!(closure(), 3);
+//! ```
+//!
 //! # Examples
 //!
 //! ## Spawning a Thread
@@ -237,12 +283,168 @@
 //! lines) and return type specifications can also be used same as in regular
 //! closures.
 //!
+//! ## Weak Captures
+//!
+//! Use `weak var` (or `weak var as alias`) to capture a [`Rc`][std::rc::Rc] or
+//! [`Arc`][std::sync::Arc] by downgrading it to a [`Weak`][std::rc::Weak]
+//! handle *before* the closure is built. Every time the closure is called, it
+//! re-upgrades the handle and, if the strong value has already been dropped,
+//! returns early instead of running the body:
+//!
+//! ```
+//! # use closure::closure;
+//! use std::rc::Rc;
+//!
+//! let shared = Rc::new(5);
+//! let closure = closure!(weak shared, || *shared * 2);
+//!
+//! assert_eq!(closure(), 10);
+//! drop(shared);
+//! assert_eq!(closure(), i32::default());
+//! ```
+//!
+//! The value returned when the upgrade fails defaults to
+//! `Default::default()`, but can be overridden with a leading
+//! `default_return = <expr>,` directive:
+//!
+//! ```
+//! # use closure::closure;
+//! use std::rc::Rc;
+//!
+//! let shared = Rc::new(5);
+//! let closure = closure!(default_return = -1, weak shared, || *shared * 2);
+//!
+//! drop(shared);
+//! assert_eq!(closure(), -1);
+//! ```
+//!
+//! `weak` is a reserved capture keyword, matched before the generic
+//! `$IDENT var` transform described under
+//! [$IDENT-transform Binding](#ident-transform-binding), so it always
+//! downgrades and re-upgrades `var` and is never interpreted as a call to a
+//! method named `weak`.
+//!
+//! ## Async Bodies
+//!
+//! The trailing body may also be an `async move` block or an `async`
+//! closure, in which case the designated captures are still bound by
+//! ordinary `let`s beforehand, and the async block or closure moves them
+//! into the resulting future itself:
+//!
+//! ```no_run
+//! # use closure::closure;
+//! use std::rc::Rc;
+//!
+//! struct Database;
+//!
+//! impl Database {
+//!     async fn query(&self) -> i32 { 42 }
+//! }
+//!
+//! fn spawn<F: std::future::Future>(_future: F) {}
+//!
+//! let db = Rc::new(Database);
+//! spawn(closure!(clone db, async move {
+//!     db.query().await
+//! }));
+//! ```
+//!
+//! `async ||` bodies are supported the same way, expanding to a regular
+//! closure that produces the captured async block whenever it is called:
+//!
+//! ```no_run
+//! # use closure::closure;
+//! use std::sync::Arc;
+//!
+//! struct Database;
+//!
+//! impl Database {
+//!     async fn query(&self) -> i32 { 42 }
+//! }
+//!
+//! let db = Arc::new(Database);
+//! let make_query = closure!(clone db, async || db.query().await);
+//! ```
+//!
+//! An async closure may also take parameters, the same as a non-`async` one:
+//!
+//! ```no_run
+//! # use closure::closure;
+//! use std::sync::Arc;
+//!
+//! struct Database;
+//!
+//! impl Database {
+//!     async fn query(&self, id: i32) -> i32 { id }
+//! }
+//!
+//! let db = Arc::new(Database);
+//! let make_query = closure!(clone db, async move |id: i32| db.query(id).await);
+//! ```
+//!
+//! ## Disjoint Field Captures
+//!
+//! There is no dedicated syntax for moving a single field out of a struct
+//! while leaving its other fields usable: `move var.field` already does
+//! this, since Rust allows moving one field out of an owned local without
+//! disturbing its siblings, and the macro's nested-path handling expands it
+//! to exactly `let field = var.field;`:
+//!
+//! ```
+//! # use closure::closure;
+//! struct Job {
+//!     id: u32,
+//!     payload: String,
+//! }
+//!
+//! let job = Job { id: 1, payload: "work".to_string() };
+//! let closure = closure!(move job.payload, || payload.len());
+//!
+//! assert_eq!(closure(), 4);
+//! assert_eq!(job.id, 1);
+//! ```
+//!
+//! Destructuring `var` with a struct pattern would require knowing `var`'s
+//! concrete type name, which a `macro_rules!` macro has no way to discover,
+//! so there is nothing further the macro can offer here beyond what
+//! `move var.field` already provides. As with a hand-written field move, a
+//! field cannot be moved out of a type that implements `Drop`.
+//!
 //! # Limitations
 //!
 //! Any closure passed to the macro will implicitly become a `move` closure, so
 //! even variables that don't appear in the capture list but are used in the
 //! closure itself will also be moved into it.
 
+/// Converts a reference-counted smart pointer into its weak counterpart.
+///
+/// This is implemented for [`Rc`][std::rc::Rc] and [`Arc`][std::sync::Arc] so
+/// that the `weak` capture mode of [`closure!`] can downgrade either one
+/// without the caller having to spell out which kind they are using.
+pub trait Downgrade {
+    /// The weak handle produced by [`downgrade`](Downgrade::downgrade).
+    type Weak;
+
+    /// Downgrades `this` into its weak counterpart.
+    fn downgrade(this: &Self) -> Self::Weak;
+}
+
+impl<T> Downgrade for ::std::rc::Rc<T> {
+    type Weak = ::std::rc::Weak<T>;
+
+    fn downgrade(this: &Self) -> Self::Weak {
+        ::std::rc::Rc::downgrade(this)
+    }
+}
+
+impl<T> Downgrade for ::std::sync::Arc<T> {
+    type Weak = ::std::sync::Weak<T>;
+
+    fn downgrade(this: &Self) -> Self::Weak {
+        ::std::sync::Arc::downgrade(this)
+    }
+}
+
 /// A macro that allows specifying a capture list for a closure that is passed
 /// to the macro.
 ///
@@ -250,41 +452,85 @@
 /// examples.
 #[macro_export(local_inner_macros)]
 macro_rules! closure {
-    (@inner move $($ids:ident).+ , $($tail:tt)*) => {
+    (@inner ($def:expr) [$($up:tt)*] move $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let $alias = $($ids).+;
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] move $($ids:ident).+ , $($tail:tt)*) => {
         let $crate::__extract_last_ident!($($ids).+) = $($ids).+;
-        closure!(@inner $($tail)*)
+        closure!(@inner ($def) [$($up)*] $($tail)*)
     };
-    (@inner move mut $($ids:ident).+ , $($tail:tt)*) => {
+    (@inner ($def:expr) [$($up:tt)*] move mut $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let mut $alias = $($ids).+;
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] move mut $($ids:ident).+ , $($tail:tt)*) => {
         let $crate::__extract_last_ident!(mut $($ids).+) = $($ids).+;
-        closure!(@inner $($tail)*)
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] ref $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let $alias = & $($ids).+;
+        closure!(@inner ($def) [$($up)*] $($tail)*)
     };
-    (@inner ref $($ids:ident).+ , $($tail:tt)*) => {
+    (@inner ($def:expr) [$($up:tt)*] ref $($ids:ident).+ , $($tail:tt)*) => {
         let $crate::__extract_last_ident!($($ids).+) = & $($ids).+;
-        closure!(@inner $($tail)*)
+        closure!(@inner ($def) [$($up)*] $($tail)*)
     };
-    (@inner ref mut $($ids:ident).+ , $($tail:tt)*) => {
+    (@inner ($def:expr) [$($up:tt)*] ref mut $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let $alias = &mut $($ids).+;
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] ref mut $($ids:ident).+ , $($tail:tt)*) => {
         let $crate::__extract_last_ident!($($ids).+) = &mut $($ids).+;
-        closure!(@inner $($tail)*)
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] weak $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let $alias = $crate::Downgrade::downgrade(&$($ids).+);
+        closure!(@inner ($def) [$($up)* let $alias = match $alias.upgrade() {
+            ::core::option::Option::Some(__closure_weak_value) => __closure_weak_value,
+            ::core::option::Option::None => return $def,
+        };] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] weak $($ids:ident).+ , $($tail:tt)*) => {
+        let $crate::__extract_last_ident!($($ids).+) = $crate::Downgrade::downgrade(&$($ids).+);
+        closure!(@inner ($def) [$($up)* let $crate::__extract_last_ident!($($ids).+) = match $crate::__extract_last_ident!($($ids).+).upgrade() {
+            ::core::option::Option::Some(__closure_weak_value) => __closure_weak_value,
+            ::core::option::Option::None => return $def,
+        };] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] $fn:ident $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let $alias = $($ids).+.$fn();
+        closure!(@inner ($def) [$($up)*] $($tail)*)
     };
-    (@inner $fn:ident $($ids:ident).+ , $($tail:tt)*) => {
+    (@inner ($def:expr) [$($up:tt)*] $fn:ident $($ids:ident).+ , $($tail:tt)*) => {
         let $crate::__extract_last_ident!($($ids).+) = $($ids).+.$fn();
-        closure!(@inner $($tail)*)
+        closure!(@inner ($def) [$($up)*] $($tail)*)
     };
-    (@inner $fn:ident mut $($ids:ident).+ , $($tail:tt)*) => {
+    (@inner ($def:expr) [$($up:tt)*] $fn:ident mut $($ids:ident).+ as $alias:ident , $($tail:tt)*) => {
+        let mut $alias = $($ids).+.$fn();
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] $fn:ident mut $($ids:ident).+ , $($tail:tt)*) => {
         let $crate::__extract_last_ident!(mut $($ids).+) = $($ids).+.$fn();
-        closure!(@inner $($tail)*)
+        closure!(@inner ($def) [$($up)*] $($tail)*)
+    };
+    (@inner ($def:expr) [$($up:tt)*] , $($tail:tt)*) => {
+        closure!(@inner ($def) [$($up)*] $($tail)*)
     };
-    (@inner , $($tail:tt)*) => {
-        closure!(@inner $($tail)*)
+    // matches on the actual closure (w/o move); the argument list and body
+    // are split out and, if any upgrades were accumulated above, spliced in
+    // front of the body by `__closure_body!`
+    (@inner ($def:expr) [$($up:tt)*] $($closure:tt)*) => {
+        $crate::__closure_body!(($def) [$($up)*] $($closure)*)
     };
-    // matches on the actual closure (w/o move)
-    (@inner $($closure:tt)*) => {
-        $crate::__assert_closure!($($closure)*);
-        move $($closure)*
-    };    
+    // accepts an optional `default_return = <expr>,` directive, used as the
+    // return value when a `weak` capture's upgrade fails
+    (default_return = $default:expr, $($args:tt)*) => {{
+        closure! { @inner ($default) [] $($args)* }
+    }};
     // macro entry point (accepts anything)
     ($($args:tt)*) => {{
-        closure! { @inner $($args)* }
+        closure! { @inner (::core::default::Default::default()) [] $($args)* }
     }};
 }
 
@@ -297,6 +543,93 @@ macro_rules! __extract_last_ident {
     (mut $ignore:ident.$($tail:ident).+) => { $crate::__extract_last_ident!(mut $($tail).+) };
 }
 
+/// Splits a closure's argument list off of its body (the `||` token and the
+/// `|` token both need their own arm, since `||` lexes as a single token),
+/// then hands the argument list to [`__closure_params!`] to find the closing
+/// `|` one token at a time (a plain `$(:tt)*` repetition followed by a
+/// literal `|` is ambiguous to the macro parser, so it cannot be matched
+/// directly).
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __closure_body {
+    (($def:expr) [$($up:tt)*] async move { $($body:tt)* }) => {
+        async move { $($up)* $($body)* }
+    };
+    (($def:expr) [$($up:tt)*] async { $($body:tt)* }) => {
+        async move { $($up)* $($body)* }
+    };
+    (($def:expr) [$($up:tt)*] async move || -> $ret:ty $body:block) => {
+        move || async move { $($up)* let __closure_async_ret: $ret = $body; __closure_async_ret }
+    };
+    (($def:expr) [$($up:tt)*] async || -> $ret:ty $body:block) => {
+        move || async move { $($up)* let __closure_async_ret: $ret = $body; __closure_async_ret }
+    };
+    (($def:expr) [$($up:tt)*] async move || $body:expr) => {
+        move || async move { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] async || $body:expr) => {
+        move || async move { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] async move | $($rest:tt)*) => {
+        $crate::__async_closure_params!(($def) [$($up)*] () $($rest)*)
+    };
+    (($def:expr) [$($up:tt)*] async | $($rest:tt)*) => {
+        $crate::__async_closure_params!(($def) [$($up)*] () $($rest)*)
+    };
+    (($def:expr) [$($up:tt)*] async $($any:tt)*) => {
+        compile_error!(concat!(
+            "unsupported async closure/block body: `async ", stringify!($($any)*), "`"
+        ))
+    };
+    (($def:expr) [$($up:tt)*] || -> $ret:ty $body:block) => {
+        move || -> $ret { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] || $body:expr) => {
+        move || { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] | $($rest:tt)*) => {
+        $crate::__closure_params!(($def) [$($up)*] () $($rest)*)
+    };
+    (($def:expr) [$($up:tt)*] $($any:tt)*) => {
+        $crate::__assert_closure!($($any)*);
+        move $($any)*
+    };
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __closure_params {
+    (($def:expr) [$($up:tt)*] ($($params:tt)*) | -> $ret:ty $body:block) => {
+        move |$($params)*| -> $ret { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] ($($params:tt)*) | $body:expr) => {
+        move |$($params)*| { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] ($($params:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::__closure_params!(($def) [$($up)*] ($($params)* $next) $($rest)*)
+    };
+}
+
+/// Like [`__closure_params!`], but for an `async move |params| ...` body:
+/// once the closing `|` is found, the params go on a regular (non-async)
+/// closure whose body is the `async move` block wrapping the expression.
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __async_closure_params {
+    (($def:expr) [$($up:tt)*] ($($params:tt)*) | -> $ret:ty $body:block) => {
+        move |$($params)*| async move {
+            $($up)*
+            let __closure_async_ret: $ret = $body;
+            __closure_async_ret
+        }
+    };
+    (($def:expr) [$($up:tt)*] ($($params:tt)*) | $body:expr) => {
+        move |$($params)*| async move { $($up)* $body }
+    };
+    (($def:expr) [$($up:tt)*] ($($params:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::__async_closure_params!(($def) [$($up)*] ($($params)* $next) $($rest)*)
+    };
+}
 
 #[macro_export(local_inner_macros)]
 #[doc(hidden)]
@@ -462,4 +795,250 @@ mod test {
 
         assert_eq!(closure(), "string, now owned");
     }
+
+    #[test]
+    fn capture_by_move_as_alias() {
+        let string = "move".to_string();
+        let closure = closure!(move string as renamed, || renamed.len());
+        assert_eq!(closure(), 4);
+    }
+
+    #[test]
+    fn capture_by_ref_as_alias() {
+        let var = -1;
+        let closure = closure!(ref var as renamed, || *renamed == -1);
+        assert!(closure());
+    }
+
+    #[test]
+    fn capture_nested_as_alias() {
+        let foo = Foo::new(-1);
+        let closure = closure!(move foo.bar.baz as renamed, |expected| renamed == expected);
+        assert!(closure(-1));
+    }
+
+    #[test]
+    fn capture_as_alias_avoids_collision() {
+        let a = Foo::new(1);
+        let b = Foo::new(2);
+        let closure = closure!(
+            move a.bar.baz as a_baz,
+            move b.bar.baz as b_baz,
+            || a_baz + b_baz
+        );
+        assert_eq!(closure(), 3);
+    }
+
+    #[test]
+    fn capture_by_clone_as_alias() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(Foo::new(0));
+        let closure = closure!(clone rc as rc_clone, |expected| -> bool {
+            rc_clone.bar.baz == expected && Rc::strong_count(&rc) == 2
+        });
+        assert!(closure(0));
+    }
+
+    #[test]
+    fn capture_by_weak_rc_upgrades() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(5);
+        let closure = closure!(weak rc, || *rc * 2);
+        assert_eq!(closure(), 10);
+    }
+
+    #[test]
+    fn capture_by_weak_rc_dropped_returns_default() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(5);
+        let closure = closure!(weak rc, || *rc * 2);
+        drop(rc);
+        assert_eq!(closure(), i32::default());
+    }
+
+    #[test]
+    fn capture_by_weak_arc_upgrades() {
+        use std::sync::Arc;
+
+        let arc = Arc::new(5);
+        let closure = closure!(weak arc, || *arc * 2);
+        assert_eq!(closure(), 10);
+    }
+
+    #[test]
+    fn capture_by_weak_as_alias() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(5);
+        let closure = closure!(weak rc as shared, || *shared * 2);
+        assert_eq!(closure(), 10);
+    }
+
+    #[test]
+    fn capture_by_weak_custom_default_return() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(5);
+        let closure = closure!(default_return = -1, weak rc, || *rc * 2);
+        drop(rc);
+        assert_eq!(closure(), -1);
+    }
+
+    #[test]
+    fn capture_by_weak_mixed_with_clone() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(5);
+        let plain = 1;
+        let closure = closure!(weak rc, clone plain, || *rc * 2 + plain);
+        assert_eq!(closure(), 11);
+    }
+
+    #[test]
+    fn capture_by_weak_is_reserved_over_inherent_method() {
+        use std::rc::Rc;
+
+        struct Foo;
+
+        impl Foo {
+            // an inherent method sharing a name with the reserved `weak`
+            // keyword; `weak foo` must still downgrade/upgrade `foo` and
+            // never call this method
+            fn weak(&self) -> i32 {
+                -1
+            }
+        }
+
+        let foo = Rc::new(Foo);
+        let closure = closure!(weak foo, || foo.weak());
+        assert_eq!(closure(), -1);
+    }
+
+    /// Polls a future to completion, panicking if it is not ready on the
+    /// first poll. Good enough to test futures that never actually await a
+    /// pending value, without pulling in an executor dependency.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn noop(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("future was not ready on first poll"),
+        }
+    }
+
+    #[test]
+    fn capture_by_clone_async_move_block() {
+        use std::rc::Rc;
+
+        let state = Rc::new(5);
+        let future = closure!(clone state, async move { *state * 2 });
+        assert_eq!(block_on(future), 10);
+    }
+
+    #[test]
+    fn capture_by_clone_async_closure() {
+        use std::rc::Rc;
+
+        let state = Rc::new(5);
+        let make_future = closure!(clone state, async || *state * 2);
+        assert_eq!(block_on(make_future()), 10);
+    }
+
+    #[test]
+    fn capture_by_clone_async_closure_with_params() {
+        use std::rc::Rc;
+
+        let state = Rc::new(5);
+        let make_future = closure!(clone state, async move |x: i32| *state + x);
+        assert_eq!(block_on(make_future(3)), 8);
+    }
+
+    #[test]
+    fn capture_by_clone_async_closure_with_params_and_return_type() {
+        use std::rc::Rc;
+
+        let state = Rc::new(5);
+        let make_future = closure!(clone state, async move |x: i32| -> i32 { *state + x });
+        assert_eq!(block_on(make_future(3)), 8);
+    }
+
+    #[test]
+    fn capture_by_clone_async_closure_with_return_type() {
+        use std::rc::Rc;
+
+        let state = Rc::new(5);
+        let make_future = closure!(clone state, async move || -> i32 { *state * 2 });
+        assert_eq!(block_on(make_future()), 10);
+    }
+
+    #[test]
+    fn capture_weak_with_async_move_block() {
+        use std::rc::Rc;
+
+        let state = Rc::new(5);
+        let future = closure!(weak state, async move { *state * 2 });
+        assert_eq!(block_on(future), 10);
+    }
+
+    #[test]
+    fn capture_by_move_leaves_siblings_usable() {
+        struct Job {
+            id: u32,
+            payload: String,
+        }
+
+        let job = Job { id: 1, payload: "work".to_string() };
+        let closure = closure!(move job.payload, || payload.len());
+
+        assert_eq!(closure(), 4);
+        assert_eq!(job.id, 1);
+    }
+
+    #[test]
+    fn capture_by_move_field_as_alias() {
+        struct Job {
+            id: u32,
+            payload: String,
+        }
+
+        let job = Job { id: 1, payload: "work".to_string() };
+        let closure = closure!(move job.payload as owned_payload, || owned_payload.len());
+
+        assert_eq!(closure(), 4);
+        assert_eq!(job.id, 1);
+    }
+
+    #[test]
+    fn capture_by_fn_ident_named_take() {
+        let mut opt = Some(5);
+        let closure = closure!(take opt, || opt);
+
+        assert_eq!(closure(), Some(5));
+        assert_eq!(opt, None);
+    }
+
+    #[test]
+    fn capture_by_fn_ident_named_take_on_dotted_path() {
+        struct Holder {
+            opt: Option<i32>,
+        }
+
+        let mut holder = Holder { opt: Some(5) };
+        let closure = closure!(take holder.opt, || opt);
+
+        assert_eq!(closure(), Some(5));
+        assert_eq!(holder.opt, None);
+    }
 }